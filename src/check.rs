@@ -0,0 +1,50 @@
+use crate::command::{host_target_triple, run_toolchain_command};
+use crate::config::CmdMatches;
+use crate::errors::TResult;
+use crate::fetch::{install_toolchain, is_toolchain_installed};
+use crate::ui::Printer;
+use rust_releases::semver;
+
+/// The outcome of running the configured check command against a single Rust version.
+pub enum CheckStatus {
+    /// The check command succeeded for this toolchain.
+    Success {
+        version: semver::Version,
+        toolchain: String,
+    },
+    /// The check command failed for this toolchain.
+    Failure {
+        version: semver::Version,
+        toolchain: String,
+    },
+}
+
+/// Installs (if necessary) and runs the configured check command against `version`.
+pub fn check_toolchain(
+    version: &semver::Version,
+    config: &CmdMatches,
+    ui: &Printer,
+) -> TResult<CheckStatus> {
+    let toolchain = format!("{}-{}", version, host_target_triple()?);
+
+    if is_toolchain_installed(&toolchain)? {
+        ui.show_reusing(&toolchain);
+    } else {
+        ui.show_installing(&toolchain);
+        install_toolchain(&toolchain)?;
+    }
+
+    let success = run_toolchain_command(&toolchain, config.check_command(), config.target())?;
+
+    Ok(if success {
+        CheckStatus::Success {
+            version: version.clone(),
+            toolchain,
+        }
+    } else {
+        CheckStatus::Failure {
+            version: version.clone(),
+            toolchain,
+        }
+    })
+}
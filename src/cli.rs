@@ -0,0 +1,125 @@
+use crate::config::{CmdMatches, ModeIntent, SearchMethod};
+use crate::errors::{CargoMSRVError, TResult};
+use crate::partial_version::PartialVersion;
+use clap::{App, Arg, ArgMatches};
+
+const DEFAULT_CHECK_COMMAND: &[&str] = &["cargo", "build"];
+
+/// Builds the `cargo msrv` clap command line definition.
+pub fn cli() -> App<'static, 'static> {
+    App::new("cargo-msrv")
+        .bin_name("cargo")
+        .subcommand(
+            App::new("msrv")
+                .about("Find the minimum supported Rust version (MSRV) for your project")
+                .arg(
+                    Arg::with_name("min")
+                        .long("min")
+                        .takes_value(true)
+                        .help("Earliest version to take into account"),
+                )
+                .arg(
+                    Arg::with_name("max")
+                        .long("max")
+                        .takes_value(true)
+                        .help("Latest version to take into account"),
+                )
+                .arg(
+                    Arg::with_name("include-all-patch-releases")
+                        .long("include-all-patch-releases")
+                        .help("Include all patch releases, instead of only the last"),
+                )
+                .arg(
+                    Arg::with_name("command")
+                        .long("command")
+                        .takes_value(true)
+                        .multiple(true)
+                        .help("Check command to be used to validate if a Rust version is compatible"),
+                )
+                .arg(
+                    Arg::with_name("bisect")
+                        .long("bisect")
+                        .conflicts_with("linear")
+                        .help("Binary search the minimum supported Rust version (default)"),
+                )
+                .arg(
+                    Arg::with_name("linear")
+                        .long("linear")
+                        .conflicts_with("bisect")
+                        .help("Linearly search the minimum supported Rust version, from latest to earliest"),
+                )
+                .arg(
+                    Arg::with_name("write-msrv")
+                        .long("write-msrv")
+                        .help("Write the MSRV to the `rust-version` field of the target Cargo.toml, once found"),
+                )
+                .arg(
+                    Arg::with_name("minimal-versions")
+                        .long("minimal-versions")
+                        .help("Check against the lowest dependency versions allowed, instead of the newest (requires a nightly toolchain)"),
+                )
+                .subcommand(
+                    App::new("verify").about(
+                        "Verify that the `rust-version` already declared in Cargo.toml compiles",
+                    ),
+                ),
+        )
+}
+
+/// Converts the parsed `ArgMatches` into a [`CmdMatches`], resolving defaults along the way.
+pub fn cmd_matches(matches: &ArgMatches) -> TResult<CmdMatches> {
+    let matches = matches.subcommand_matches("msrv").unwrap_or(matches);
+
+    let target = std::env::current_dir()
+        .map_err(CargoMSRVError::Io)?
+        .to_string_lossy()
+        .to_string();
+
+    let minimum_version = matches
+        .value_of("min")
+        .map(str::parse::<PartialVersion>)
+        .transpose()
+        .map_err(|_| CargoMSRVError::GenericMessage("unable to parse `--min` as a version".to_string()))?;
+
+    let maximum_version = matches
+        .value_of("max")
+        .map(str::parse::<PartialVersion>)
+        .transpose()
+        .map_err(|_| CargoMSRVError::GenericMessage("unable to parse `--max` as a version".to_string()))?;
+
+    let include_all_patch_releases = matches.is_present("include-all-patch-releases");
+    let write_msrv = matches.is_present("write-msrv");
+    let minimal_versions = matches.is_present("minimal-versions");
+
+    let mode_intent = if matches.subcommand_matches("verify").is_some() {
+        ModeIntent::VerifyMSRV
+    } else {
+        ModeIntent::DetermineMSRV
+    };
+
+    let search_method = if matches.is_present("linear") {
+        SearchMethod::Linear
+    } else {
+        SearchMethod::Bisect
+    };
+
+    let check_command = matches
+        .values_of("command")
+        .map(|values| values.map(String::from).collect::<Vec<_>>())
+        .unwrap_or_else(|| {
+            DEFAULT_CHECK_COMMAND
+                .iter()
+                .map(|s| s.to_string())
+                .collect()
+        });
+
+    Ok(CmdMatches::new(target)
+        .with_minimum_version(minimum_version)
+        .with_maximum_version(maximum_version)
+        .with_include_all_patch_releases(include_all_patch_releases)
+        .with_check_command(check_command)
+        .with_search_method(search_method)
+        .with_write_msrv(write_msrv)
+        .with_mode_intent(mode_intent)
+        .with_minimal_versions(minimal_versions))
+}
@@ -0,0 +1,48 @@
+use crate::errors::{CargoMSRVError, TResult};
+use std::process::Command;
+
+/// Determines the host target triple (e.g. `x86_64-unknown-linux-gnu`) by asking `rustc`.
+pub fn host_target_triple() -> TResult<String> {
+    let output = Command::new("rustc").arg("-vV").output()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(str::to_string)
+        .ok_or_else(|| CargoMSRVError::GenericMessage("unable to determine host target".to_string()))
+}
+
+/// Re-resolves the lockfile in `dir` to the lowest dependency versions permitted by their
+/// requirements, via cargo's unstable `minimal-versions` resolver. This always requires the
+/// nightly toolchain, regardless of which toolchain is under test.
+pub fn resolve_minimal_version_dependencies(dir: &str) -> TResult<()> {
+    let status = Command::new("cargo")
+        .args(&["+nightly", "update", "-Z", "minimal-versions"])
+        .current_dir(dir)
+        .status()?;
+
+    if !status.success() {
+        return Err(CargoMSRVError::GenericMessage(
+            "unable to resolve minimal-version dependencies; is the nightly toolchain installed?"
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Runs `command` (e.g. `cargo build`) for the given `toolchain` (e.g. `1.56.0-x86_64-unknown-linux-gnu`)
+/// inside `dir`, returning whether the process exited successfully.
+pub fn run_toolchain_command(toolchain: &str, command: &[String], dir: &str) -> TResult<bool> {
+    let (bin, args) = command.split_first().expect("check command must not be empty");
+
+    let status = Command::new("rustup")
+        .arg("run")
+        .arg(toolchain)
+        .arg(bin)
+        .args(args)
+        .current_dir(dir)
+        .status()?;
+
+    Ok(status.success())
+}
@@ -0,0 +1,147 @@
+use crate::partial_version::PartialVersion;
+
+/// Selects how the set of candidate releases is searched for the MSRV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMethod {
+    /// Binary-search the sorted candidate releases for the lowest compatible version.
+    ///
+    /// Relies on compatibility being monotonic in the Rust version, i.e. if version `x`
+    /// compiles, every later stable version also compiles. This does not always hold (for
+    /// example for crates which gate code behind version-specific `cfg`s), in which case
+    /// [`SearchMethod::Linear`] should be used instead.
+    Bisect,
+    /// Check the candidate releases one by one, starting from the latest and stopping at the
+    /// first one that fails.
+    Linear,
+}
+
+impl Default for SearchMethod {
+    fn default() -> Self {
+        Self::Bisect
+    }
+}
+
+/// Selects the overall action `cargo msrv` takes for an invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeIntent {
+    /// Search for the MSRV, as with a plain `cargo msrv`.
+    DetermineMSRV,
+    /// Verify that the `rust-version` already declared in `Cargo.toml` is correct, as with
+    /// `cargo msrv verify`.
+    VerifyMSRV,
+}
+
+impl Default for ModeIntent {
+    fn default() -> Self {
+        Self::DetermineMSRV
+    }
+}
+
+/// Carries the fully resolved set of options for a single `cargo msrv` invocation.
+///
+/// Constructed from the parsed CLI arguments in [`crate::cli`], and consumed by
+/// [`crate::determine_msrv`] and [`crate::check::check_toolchain`].
+#[derive(Debug, Clone)]
+pub struct CmdMatches {
+    target: String,
+    minimum_version: Option<PartialVersion>,
+    maximum_version: Option<PartialVersion>,
+    include_all_patch_releases: bool,
+    check_command: Vec<String>,
+    search_method: SearchMethod,
+    write_msrv: bool,
+    mode_intent: ModeIntent,
+    minimal_versions: bool,
+}
+
+impl CmdMatches {
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            minimum_version: None,
+            maximum_version: None,
+            include_all_patch_releases: false,
+            check_command: vec!["cargo".to_string(), "build".to_string()],
+            search_method: SearchMethod::default(),
+            write_msrv: false,
+            mode_intent: ModeIntent::default(),
+            minimal_versions: false,
+        }
+    }
+
+    pub fn with_minimum_version(mut self, version: Option<PartialVersion>) -> Self {
+        self.minimum_version = version;
+        self
+    }
+
+    pub fn with_maximum_version(mut self, version: Option<PartialVersion>) -> Self {
+        self.maximum_version = version;
+        self
+    }
+
+    pub fn with_include_all_patch_releases(mut self, include: bool) -> Self {
+        self.include_all_patch_releases = include;
+        self
+    }
+
+    pub fn with_check_command(mut self, command: Vec<String>) -> Self {
+        self.check_command = command;
+        self
+    }
+
+    pub fn with_search_method(mut self, search_method: SearchMethod) -> Self {
+        self.search_method = search_method;
+        self
+    }
+
+    pub fn with_write_msrv(mut self, write_msrv: bool) -> Self {
+        self.write_msrv = write_msrv;
+        self
+    }
+
+    pub fn with_mode_intent(mut self, mode_intent: ModeIntent) -> Self {
+        self.mode_intent = mode_intent;
+        self
+    }
+
+    pub fn with_minimal_versions(mut self, minimal_versions: bool) -> Self {
+        self.minimal_versions = minimal_versions;
+        self
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn minimum_version(&self) -> Option<&PartialVersion> {
+        self.minimum_version.as_ref()
+    }
+
+    pub fn maximum_version(&self) -> Option<&PartialVersion> {
+        self.maximum_version.as_ref()
+    }
+
+    pub fn include_all_patch_releases(&self) -> bool {
+        self.include_all_patch_releases
+    }
+
+    pub fn check_command(&self) -> &[String] {
+        &self.check_command
+    }
+
+    pub fn search_method(&self) -> SearchMethod {
+        self.search_method
+    }
+
+    pub fn write_msrv(&self) -> bool {
+        self.write_msrv
+    }
+
+    pub fn mode_intent(&self) -> ModeIntent {
+        self.mode_intent
+    }
+
+    pub fn minimal_versions(&self) -> bool {
+        self.minimal_versions
+    }
+}
@@ -0,0 +1,95 @@
+use crate::config::CmdMatches;
+use crate::errors::{CargoMSRVError, TResult};
+use crate::manifest::manifest_path;
+use crate::partial_version::{is_compatible_with, PartialVersion};
+use cargo_metadata::{DependencyKind, MetadataCommand, Node, PackageId, Resolve};
+use rust_releases::semver;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A resolved dependency whose own declared `rust-version` is higher than the Rust version
+/// currently under test, making it responsible for a check failure.
+#[derive(Debug, Clone)]
+pub struct OffendingDependency {
+    pub name: String,
+    pub version: String,
+    pub rust_version: String,
+}
+
+/// Resolves the dependency graph for the target package and returns every normal (non-dev,
+/// non-build) dependency in it whose declared `rust-version` is incompatible with
+/// `rustc_version`. The root package itself is never considered a dependency of itself.
+pub fn find_offending_dependencies(
+    config: &CmdMatches,
+    rustc_version: &semver::Version,
+) -> TResult<Vec<OffendingDependency>> {
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path(config.target()))
+        .exec()
+        .map_err(|error| {
+            CargoMSRVError::GenericMessage(format!("unable to resolve dependency graph: {}", error))
+        })?;
+
+    let normal_deps = metadata
+        .resolve
+        .as_ref()
+        .and_then(|resolve| resolve.root.as_ref().map(|root| (resolve, root)))
+        .map(|(resolve, root)| normal_dependency_ids(resolve, root))
+        .unwrap_or_default();
+
+    let offenders = metadata
+        .packages
+        .iter()
+        .filter(|package| normal_deps.contains(&package.id))
+        .filter_map(|package| {
+            // `cargo_metadata` parses `rust-version` into a `semver::Version` for us, so there's
+            // no string to re-parse here.
+            let declared = package.rust_version.as_ref()?;
+            let msrv = PartialVersion::from(declared);
+
+            if is_compatible_with(&msrv, rustc_version) {
+                None
+            } else {
+                Some(OffendingDependency {
+                    name: package.name.clone(),
+                    version: package.version.to_string(),
+                    rust_version: declared.to_string(),
+                })
+            }
+        })
+        .collect();
+
+    Ok(offenders)
+}
+
+/// Returns every package id reachable from `root` via a normal dependency edge (i.e. excluding
+/// dev-dependencies and build-dependencies, which aren't compiled as part of a regular check),
+/// not including `root` itself.
+fn normal_dependency_ids(resolve: &Resolve, root: &PackageId) -> HashSet<PackageId> {
+    let nodes: HashMap<&PackageId, &Node> = resolve.nodes.iter().map(|node| (&node.id, node)).collect();
+
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(root.clone());
+
+    while let Some(id) = queue.pop_front() {
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+
+        if let Some(node) = nodes.get(&id) {
+            for dep in &node.deps {
+                let is_normal = dep
+                    .dep_kinds
+                    .iter()
+                    .any(|dep_kind| dep_kind.kind == DependencyKind::Normal);
+
+                if is_normal {
+                    queue.push_back(dep.pkg.clone());
+                }
+            }
+        }
+    }
+
+    visited.remove(root);
+    visited
+}
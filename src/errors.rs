@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+/// Shorthand for `Result<T, CargoMSRVError>`, the result type used throughout this crate.
+pub type TResult<T> = Result<T, CargoMSRVError>;
+
+#[derive(Debug, Error)]
+pub enum CargoMSRVError {
+    #[error("Unable to find a Minimum Supported Rust Version (MSRV). Last checked rustc command: `{command}`")]
+    UnableToFindAnyGoodVersion { command: String },
+
+    #[error("No crate root could be found for the current workspace")]
+    UnableToFindRootPackage,
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    RustReleases(#[from] rust_releases::RustReleasesError),
+
+    #[error("Unable to parse version: {0}")]
+    InvalidVersion(#[from] rust_releases::semver::Error),
+
+    #[error("No `rust-version` was found in `{manifest_path}`")]
+    NoMSRVInManifest { manifest_path: String },
+
+    #[error("rustup was not found on PATH; cargo-msrv requires rustup to install and run toolchains")]
+    RustupNotFound,
+
+    #[error("The declared MSRV `{rust_version}` does not compile with toolchain `{toolchain}`")]
+    RustVersionNotCompatible {
+        rust_version: String,
+        toolchain: String,
+    },
+
+    #[error("{0}")]
+    GenericMessage(String),
+}
@@ -0,0 +1,40 @@
+use crate::errors::{CargoMSRVError, TResult};
+use std::process::Command;
+
+/// Returns the toolchains `rustup` already has installed, e.g. `1.56.0-x86_64-unknown-linux-gnu`.
+///
+/// Fails with [`CargoMSRVError::RustupNotFound`] if `rustup` is not on `PATH`.
+pub fn installed_toolchains() -> TResult<Vec<String>> {
+    let output = Command::new("rustup")
+        .args(&["toolchain", "list"])
+        .output()
+        .map_err(|_| CargoMSRVError::RustupNotFound)?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Whether `toolchain` is already installed, according to `rustup`.
+pub fn is_toolchain_installed(toolchain: &str) -> TResult<bool> {
+    Ok(installed_toolchains()?.iter().any(|installed| installed == toolchain))
+}
+
+/// Installs `toolchain` via `rustup`.
+pub fn install_toolchain(toolchain: &str) -> TResult<()> {
+    let status = Command::new("rustup")
+        .args(&["toolchain", "install", toolchain, "--profile", "minimal"])
+        .status()
+        .map_err(|_| CargoMSRVError::RustupNotFound)?;
+
+    if !status.success() {
+        return Err(CargoMSRVError::GenericMessage(format!(
+            "unable to install toolchain `{}`",
+            toolchain
+        )));
+    }
+
+    Ok(())
+}
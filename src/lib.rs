@@ -3,8 +3,10 @@
 
 use crate::check::{check_toolchain, CheckStatus};
 use crate::cli::cmd_matches;
-use crate::config::CmdMatches;
+use crate::config::{CmdMatches, ModeIntent, SearchMethod};
+use crate::dependencies;
 use crate::errors::{CargoMSRVError, TResult};
+use crate::partial_version::{is_compatible_with, PartialVersion};
 use crate::ui::Printer;
 use rust_releases::source::{FetchResources, RustChangelog, Source};
 use rust_releases::{semver, Channel, Release};
@@ -13,25 +15,72 @@ pub mod check;
 pub mod cli;
 pub mod command;
 pub mod config;
+pub mod dependencies;
 pub mod errors;
 pub mod fetch;
+pub mod manifest;
+pub mod partial_version;
 pub mod ui;
 
 pub fn run_cargo_msrv() -> TResult<()> {
     let matches = cli::cli().get_matches();
     let config = cmd_matches(&matches)?;
 
+    if let ModeIntent::VerifyMSRV = config.mode_intent() {
+        return verify_msrv(&config);
+    }
+
     let index_strategy = RustChangelog::fetch_channel(Channel::Stable)?;
     let index = index_strategy.build_index()?;
 
     let latest_supported = determine_msrv(&config, &index)?;
 
-    if let MinimalCompatibility::NoCompatibleToolchains = latest_supported {
-        Err(CargoMSRVError::UnableToFindAnyGoodVersion {
-            command: config.check_command().join(" "),
-        })
-    } else {
-        Ok(())
+    match &latest_supported {
+        MinimalCompatibility::NoCompatibleToolchains => {
+            Err(CargoMSRVError::UnableToFindAnyGoodVersion {
+                command: config.check_command().join(" "),
+            })
+        }
+        MinimalCompatibility::CapableToolchain { version, .. } => {
+            if config.write_msrv() {
+                manifest::set_rust_version(&manifest::manifest_path(config.target()), version)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Checks that the `rust-version` already declared in the target manifest actually compiles,
+/// instead of searching the full release range as [`determine_msrv`] does.
+fn verify_msrv(config: &CmdMatches) -> TResult<()> {
+    let manifest_path = manifest::manifest_path(config.target());
+
+    let rust_version = manifest::read_rust_version(&manifest_path)?.ok_or_else(|| {
+        CargoMSRVError::NoMSRVInManifest {
+            manifest_path: manifest_path.display().to_string(),
+        }
+    })?;
+
+    let version = to_full_version(&rust_version.parse()?);
+
+    if config.minimal_versions() {
+        command::resolve_minimal_version_dependencies(config.target())?;
+    }
+
+    let ui = Printer::new(1);
+    ui.welcome_verify(config.target(), &config.check_command().join(" "));
+    ui.show_progress("Checking", &version);
+
+    match check_toolchain(&version, config, &ui)? {
+        CheckStatus::Success { .. } => {
+            ui.finish_with_ok(&version);
+            Ok(())
+        }
+        CheckStatus::Failure { toolchain, .. } => Err(CargoMSRVError::RustVersionNotCompatible {
+            rust_version,
+            toolchain,
+        }),
     }
 }
 
@@ -83,6 +132,13 @@ pub fn determine_msrv(
     let ui = Printer::new(releases.len() as u64);
     ui.welcome(config.target(), &cmd);
 
+    // Minimal-version resolution only depends on the dependency requirements, not on the rustc
+    // toolchain under test, so it only needs to run once before the search starts, not on every
+    // candidate.
+    if config.minimal_versions() {
+        command::resolve_minimal_version_dependencies(config.target())?;
+    }
+
     // The collecting step is necessary, because Rust can't deal with equal opaque types
     let releases = if config.include_all_patch_releases() {
         index.all_releases_iterator().collect::<Vec<_>>()
@@ -90,14 +146,23 @@ pub fn determine_msrv(
         index.stable_releases_iterator().collect::<Vec<_>>()
     };
 
-    let included_releases = releases.iter().filter(|release| include_version(release.version(), config.minimum_version(), config.maximum_version()));
+    let included_releases = releases
+        .iter()
+        .copied()
+        .filter(|release| include_version(release.version(), config.minimum_version(), config.maximum_version()))
+        .collect::<Vec<_>>();
 
-    test_against_releases_linearly(
-        included_releases,
-        &mut compatibility,
-        config,
-        &ui,
-    )?;
+    match config.search_method() {
+        SearchMethod::Bisect => {
+            test_against_releases_bisect(&included_releases, &mut compatibility, config, &ui)?
+        }
+        SearchMethod::Linear => test_against_releases_linearly(
+            included_releases.iter(),
+            &mut compatibility,
+            config,
+            &ui,
+        )?,
+    }
 
     match &compatibility {
         MinimalCompatibility::CapableToolchain {
@@ -125,7 +190,8 @@ where
         ui.show_progress("Checking", release.version());
         let status = check_toolchain(release.version(), config, ui)?;
 
-        if let CheckStatus::Failure { .. } = status {
+        if let CheckStatus::Failure { version, .. } = &status {
+            report_failure(config, ui, version)?;
             break;
         }
 
@@ -135,12 +201,136 @@ where
     Ok(())
 }
 
-fn include_version(current: &semver::Version, min_version: Option<&semver::Version>, max_version: Option<&semver::Version>) -> bool {
-    match (min_version, max_version) {
-        (Some(min), Some(max)) => current >= min && current <= max,
-        (Some(min), None) => current >= min,
-        (None, Some(max)) => current <= max,
-        (None, None) => true,
+/// Prints why `version` failed its check, naming any resolved dependency whose own
+/// `rust-version` rules it out, or falling back to a plain failure message if none do.
+fn report_failure(config: &CmdMatches, ui: &Printer, version: &semver::Version) -> TResult<()> {
+    let offenders = dependencies::find_offending_dependencies(config, version)?;
+
+    if offenders.is_empty() {
+        ui.show_failure(version);
+    } else {
+        ui.show_failure_with_guidance(version, &offenders);
+    }
+
+    Ok(())
+}
+
+/// Binary-searches `releases` for the lowest compatible version.
+///
+/// This relies on compatibility being monotonic in the Rust version: if version `x` compiles,
+/// every later stable version is assumed to compile as well. `releases` does not need to be
+/// pre-sorted; it is sorted ascending (oldest first) here before the search starts.
+fn test_against_releases_bisect(
+    releases: &[&Release],
+    compatibility: &mut MinimalCompatibility,
+    config: &CmdMatches,
+    ui: &Printer,
+) -> TResult<()> {
+    if releases.is_empty() {
+        return Ok(());
+    }
+
+    let mut releases = releases.to_vec();
+    releases.sort_by(|a, b| a.version().cmp(b.version()));
+
+    let mut checked: Vec<Option<CheckStatus>> = (0..releases.len()).map(|_| None).collect();
+    let mut error = None;
+
+    let found = bisect_compatible_index(releases.len(), |index| {
+        if let Some(status) = &checked[index] {
+            return matches!(status, CheckStatus::Success { .. });
+        }
+
+        match run_check(&releases, index, config, ui) {
+            Ok(status) => {
+                let is_success = matches!(status, CheckStatus::Success { .. });
+
+                if let CheckStatus::Failure { version, .. } = &status {
+                    if let Err(report_error) = report_failure(config, ui, version) {
+                        error.get_or_insert(report_error);
+                    }
+                }
+
+                checked[index] = Some(status);
+                is_success
+            }
+            Err(check_error) => {
+                error.get_or_insert(check_error);
+                false
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    if let Some(index) = found {
+        if let Some(status) = checked[index].take() {
+            *compatibility = status.into();
+        }
+    }
+
+    Ok(())
+}
+
+/// Binary-searches `0..len` for the lowest index for which `is_compatible` returns `true`.
+///
+/// Assumes `is_compatible` is monotonic: once it returns `true` for some index, it is assumed to
+/// return `true` for every higher index too. Returns `None` if `is_compatible` returns `false`
+/// even for `len - 1`, i.e. nothing in range is compatible.
+fn bisect_compatible_index(len: usize, mut is_compatible: impl FnMut(usize) -> bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let newest = len - 1;
+
+    if !is_compatible(newest) {
+        return None;
+    }
+
+    let mut lo = 0;
+    let mut hi = newest;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if is_compatible(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    Some(lo)
+}
+
+fn run_check(
+    releases: &[&Release],
+    index: usize,
+    config: &CmdMatches,
+    ui: &Printer,
+) -> TResult<CheckStatus> {
+    let release = releases[index];
+    ui.show_progress("Checking", release.version());
+    check_toolchain(release.version(), config, ui)
+}
+
+fn include_version(current: &semver::Version, min_version: Option<&PartialVersion>, max_version: Option<&PartialVersion>) -> bool {
+    let above_min = min_version.map_or(true, |min| is_compatible_with(min, current));
+    let below_max = max_version.map_or(true, |max| is_compatible_with(&PartialVersion::from(current), &to_full_version(max)));
+
+    above_min && below_max
+}
+
+/// Fills in any omitted components of `version` with `0`, so it can be compared like a regular
+/// [`semver::Version`].
+fn to_full_version(version: &PartialVersion) -> semver::Version {
+    match *version {
+        PartialVersion::Major(major) => semver::Version::new(major, 0, 0),
+        PartialVersion::MajorMinor(major, minor) => semver::Version::new(major, minor, 0),
+        PartialVersion::Full(major, minor, patch) => semver::Version::new(major, minor, patch),
     }
 }
 
@@ -173,8 +363,8 @@ mod tests {
     })]
     fn test_included_versions(current: u64, min: Option<u64>, max: Option<u64>) {
         let current = Version::new(1, current, 0);
-        let min_version = min.map(|m| Version::new(1, m, 0));
-        let max_version = max.map(|m| Version::new(1, m, 0));
+        let min_version = min.map(|m| PartialVersion::MajorMinor(1, m));
+        let max_version = max.map(|m| PartialVersion::MajorMinor(1, m));
 
         assert!(include_version(&current, min_version.as_ref(), max_version.as_ref()));
     }
@@ -197,9 +387,61 @@ mod tests {
     })]
     fn test_excluded_versions(current: u64, min: Option<u64>, max: Option<u64>) {
         let current = Version::new(1, current, 0);
-        let min_version = min.map(|m| Version::new(1, m, 0));
-        let max_version = max.map(|m| Version::new(1, m, 0));
+        let min_version = min.map(|m| PartialVersion::MajorMinor(1, m));
+        let max_version = max.map(|m| PartialVersion::MajorMinor(1, m));
 
         assert!(!include_version(&current, min_version.as_ref(), max_version.as_ref()));
     }
+
+    /// A synthetic compatibility oracle: everything from `threshold` onwards is compatible,
+    /// everything before it is not.
+    fn compatible_from(threshold: usize) -> impl FnMut(usize) -> bool {
+        move |index| index >= threshold
+    }
+
+    #[test]
+    fn bisect_finds_the_lower_boundary() {
+        assert_eq!(bisect_compatible_index(10, compatible_from(4)), Some(4));
+    }
+
+    #[test]
+    fn bisect_single_release_list() {
+        assert_eq!(bisect_compatible_index(1, compatible_from(0)), Some(0));
+        assert_eq!(bisect_compatible_index(1, compatible_from(1)), None);
+    }
+
+    #[test]
+    fn bisect_only_newest_is_compatible() {
+        assert_eq!(bisect_compatible_index(10, compatible_from(9)), Some(9));
+    }
+
+    #[test]
+    fn bisect_everything_is_compatible() {
+        assert_eq!(bisect_compatible_index(10, compatible_from(0)), Some(0));
+    }
+
+    #[test]
+    fn bisect_nothing_is_compatible() {
+        assert_eq!(bisect_compatible_index(10, compatible_from(10)), None);
+    }
+
+    #[test]
+    fn bisect_empty_release_list() {
+        assert_eq!(bisect_compatible_index(0, compatible_from(0)), None);
+    }
+
+    #[test]
+    fn bisect_only_probes_as_needed() {
+        let mut probed = Vec::new();
+        let threshold = 4;
+
+        let found = bisect_compatible_index(10, |index| {
+            probed.push(index);
+            index >= threshold
+        });
+
+        assert_eq!(found, Some(threshold));
+        // log2(10) probes to narrow in, plus the upfront newest-release check.
+        assert!(probed.len() <= 5, "bisect probed {} indices, expected at most 5", probed.len());
+    }
 }
@@ -0,0 +1,39 @@
+use crate::errors::{CargoMSRVError, TResult};
+use rust_releases::semver;
+use std::path::{Path, PathBuf};
+use toml_edit::{value, Document};
+
+/// Returns the path to the `Cargo.toml` of the package rooted at `target_dir`.
+pub fn manifest_path(target_dir: &str) -> PathBuf {
+    Path::new(target_dir).join("Cargo.toml")
+}
+
+/// Reads `[package] rust-version` from the manifest at `manifest_path`, if present.
+pub fn read_rust_version(manifest_path: &Path) -> TResult<Option<String>> {
+    let contents = std::fs::read_to_string(manifest_path)?;
+
+    let document = contents.parse::<Document>().map_err(|error| {
+        CargoMSRVError::GenericMessage(format!("unable to parse `{}`: {}", manifest_path.display(), error))
+    })?;
+
+    Ok(document["package"]["rust-version"]
+        .as_str()
+        .map(str::to_string))
+}
+
+/// Sets `[package] rust-version` in the manifest at `manifest_path` to `version`, inserting the
+/// key if it is absent. Uses `toml_edit` so any other formatting and comments in the manifest
+/// are preserved.
+pub fn set_rust_version(manifest_path: &Path, version: &semver::Version) -> TResult<()> {
+    let contents = std::fs::read_to_string(manifest_path)?;
+
+    let mut document = contents.parse::<Document>().map_err(|error| {
+        CargoMSRVError::GenericMessage(format!("unable to parse `{}`: {}", manifest_path.display(), error))
+    })?;
+
+    document["package"]["rust-version"] = value(version.to_string());
+
+    std::fs::write(manifest_path, document.to_string())?;
+
+    Ok(())
+}
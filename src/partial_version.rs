@@ -0,0 +1,105 @@
+use rust_releases::semver;
+use std::str::FromStr;
+
+/// A version as a user may write it when declaring an MSRV, e.g. `1`, `1.56` or `1.56.0`.
+///
+/// Unlike a full [`semver::Version`], components after the first may be omitted. This mirrors
+/// how crate authors write `rust-version` in `Cargo.toml`: `rust-version = "1.56"` is just as
+/// valid as `rust-version = "1.56.0"`, and both mean the same thing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialVersion {
+    Major(u64),
+    MajorMinor(u64, u64),
+    Full(u64, u64, u64),
+}
+
+impl PartialVersion {
+    /// Converts this partial version into a caret requirement, e.g. `1.56` becomes the
+    /// requirement `^1.56.0`, which matches any `1.56.0 <= version < 2.0.0`.
+    pub fn to_caret_requirement(&self) -> semver::VersionReq {
+        let version = match self {
+            Self::Major(major) => format!("^{}", major),
+            Self::MajorMinor(major, minor) => format!("^{}.{}", major, minor),
+            Self::Full(major, minor, patch) => format!("^{}.{}.{}", major, minor, patch),
+        };
+
+        // A caret requirement built from a valid, non-negative version triple always parses.
+        semver::VersionReq::parse(&version).expect("a caret requirement of numbers is always valid")
+    }
+}
+
+impl From<&semver::Version> for PartialVersion {
+    fn from(version: &semver::Version) -> Self {
+        Self::Full(version.major, version.minor, version.patch)
+    }
+}
+
+impl FromStr for PartialVersion {
+    type Err = semver::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = s.split('.').collect::<Vec<_>>();
+
+        match components.as_slice() {
+            [major] => Ok(Self::Major(parse_component(major)?)),
+            [major, minor] => Ok(Self::MajorMinor(parse_component(major)?, parse_component(minor)?)),
+            _ => {
+                let version = semver::Version::parse(s)?;
+                Ok(Self::Full(version.major, version.minor, version.patch))
+            }
+        }
+    }
+}
+
+fn parse_component(component: &str) -> Result<u64, semver::Error> {
+    // Route single numeric components through a full parse as well, so invalid input (e.g.
+    // `1.foo`) produces the same kind of error as a full `semver::Version::parse` would.
+    semver::Version::parse(&format!("{}.0.0", component)).map(|version| version.major)
+}
+
+/// Returns whether `rustc` is able to compile code that declares `msrv` as its MSRV.
+///
+/// `msrv` is converted into a caret requirement (so `1.56` means `>=1.56.0, <2.0.0`). Any
+/// pre-release or build metadata is stripped from `rustc` first: today every caller only ever
+/// passes in a plain version (a release from the stable channel index, or the `--max` bound),
+/// so this is normally a no-op,
+/// but it guards against a future caller feeding in a toolchain-reported version (e.g. a nightly
+/// tagged `1.70.0-nightly`) without that version being rejected on a metadata technicality.
+pub fn is_compatible_with(msrv: &PartialVersion, rustc: &semver::Version) -> bool {
+    let rustc = semver::Version::new(rustc.major, rustc.minor, rustc.patch);
+    msrv.to_caret_requirement().matches(&rustc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_major_minor_and_full() {
+        assert_eq!(PartialVersion::from_str("1").unwrap(), PartialVersion::Major(1));
+        assert_eq!(
+            PartialVersion::from_str("1.56").unwrap(),
+            PartialVersion::MajorMinor(1, 56)
+        );
+        assert_eq!(
+            PartialVersion::from_str("1.56.0").unwrap(),
+            PartialVersion::Full(1, 56, 0)
+        );
+    }
+
+    #[test]
+    fn nightly_rustc_is_compatible_with_its_stable_counterpart() {
+        let msrv = PartialVersion::MajorMinor(1, 56);
+        let rustc = semver::Version::parse("1.56.0-nightly").unwrap();
+
+        assert!(is_compatible_with(&msrv, &rustc));
+    }
+
+    #[test]
+    fn older_rustc_is_not_compatible() {
+        let msrv = PartialVersion::MajorMinor(1, 56);
+        let rustc = semver::Version::new(1, 55, 0);
+
+        assert!(!is_compatible_with(&msrv, &rustc));
+    }
+}
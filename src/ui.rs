@@ -0,0 +1,74 @@
+use crate::dependencies::OffendingDependency;
+use indicatif::{ProgressBar, ProgressStyle};
+use rust_releases::semver;
+
+/// Reports the progress of an MSRV search to the user on the terminal.
+pub struct Printer {
+    bar: ProgressBar,
+}
+
+impl Printer {
+    pub fn new(steps: u64) -> Self {
+        let bar = ProgressBar::new(steps);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template("{msg:.green} [{bar:25}] {pos}/{len}"),
+        );
+
+        Self { bar }
+    }
+
+    pub fn welcome(&self, target: &str, cmd: &str) {
+        println!("Determining the Minimum Supported Rust Version (MSRV) for `{}`", target);
+        println!("Using check command `{}`", cmd);
+    }
+
+    /// Like [`Printer::welcome`], but for a [`verify_msrv`](crate::verify_msrv) run, which checks
+    /// the already-declared `rust-version` instead of searching for one.
+    pub fn welcome_verify(&self, target: &str, cmd: &str) {
+        println!("Verifying the declared Minimum Supported Rust Version (MSRV) for `{}`", target);
+        println!("Using check command `{}`", cmd);
+    }
+
+    pub fn show_installing(&self, toolchain: &str) {
+        self.bar.println(format!("Installing toolchain `{}`", toolchain));
+    }
+
+    pub fn show_reusing(&self, toolchain: &str) {
+        self.bar.println(format!("Using already-installed toolchain `{}`", toolchain));
+    }
+
+    pub fn show_progress(&self, action: &str, version: &semver::Version) {
+        self.bar.set_message(action.to_string());
+        self.bar.set_position(self.bar.position() + 1);
+        self.bar.println(format!("{} Rust '{}'", action, version));
+    }
+
+    pub fn finish_with_ok(&self, version: &semver::Version) {
+        self.bar.finish_and_clear();
+        println!("Done! MSRV is: {}", version);
+    }
+
+    pub fn finish_with_err(&self, cmd: &str) {
+        self.bar.finish_and_clear();
+        println!("Unable to find an MSRV for which `{}` succeeds", cmd);
+    }
+
+    /// Reports a plain check failure for `version`, with no known cause.
+    pub fn show_failure(&self, version: &semver::Version) {
+        self.bar.println(format!("{} rejected", version));
+    }
+
+    /// Reports a check failure for `version`, naming the dependencies responsible for it.
+    /// Mutually exclusive with [`Printer::show_failure`].
+    pub fn show_failure_with_guidance(&self, version: &semver::Version, offenders: &[OffendingDependency]) {
+        self.bar.println(format!("{} rejected:", version));
+
+        for offender in offenders {
+            self.bar.println(format!(
+                "  `{} v{}` requires Rust {}",
+                offender.name, offender.version, offender.rust_version
+            ));
+        }
+    }
+}